@@ -1,3 +1,4 @@
+use std::collections::VecDeque;
 use std::io::{self, Write};
 use std::path::PathBuf;
 use std::process;
@@ -6,7 +7,31 @@ use std::{env, fs, str};
 use clap::{arg, command, value_parser};
 use hex;
 
-pub const IMPLS: &[&str] = &["ansic", "apple", "bcpl", "bcslib", "borland_c_lrand", "borland_c_rand", "c64_a", "c64_b", "c64_c", "cpp", "cray", "derive", "drand48", "glibc_old", "glibc_type_0", "lrand48", "maple", "minstd_16807", "minstd_48271", "mmix", "mrand48", "musl", "nag", "newlib_u16", "newlib", "numrecipes", "random0", "randu", "rtl_uniform", "simscript", "super_duper", "turbo_pascal", "urn12", "vbasic6", "zx81"];
+pub const IMPLS: &[&str] = &["ansic", "apple", "bcpl", "bcslib", "borland_c_lrand", "borland_c_rand", "c64_a", "c64_b", "c64_c", "chacha20", "cpp", "cray", "derive", "drand48", "glibc_old", "glibc_type_0", "isaac", "lrand48", "maple", "minstd_16807", "minstd_48271", "mmix", "mrand48", "mt19937", "musl", "nag", "newlib_u16", "newlib", "numrecipes", "python_random", "random0", "randu", "rtl_uniform", "simscript", "super_duper", "turbo_pascal", "urn12", "vbasic6", "zx81"];
+
+// Common interface for every supported PRNG: seed it (plus an optional
+// number of silent warm-up iterations), then pull raw i64 words off of it
+// like any other iterator. `iterate`/`run` only need this, so they stay
+// agnostic to whether a given implementation is an LCG, MT19937, ISAAC, etc.
+trait Generator: Iterator<Item = i64> {
+    fn srand(&mut self, seed: i64, offset: usize);
+
+    // Implementation-specific number of silent iterations to skip by
+    // default, when the user didn't pass --offset. LCGs look this up per
+    // variant; other families just start from the first output.
+    fn default_offset(&self) -> usize {
+        0
+    }
+
+    // Modulus of this generator's raw output, used by --float mode to scale
+    // a single draw into [0, 1) (e.g. dividing by the LCG's modulo, or by
+    // 2^32 for a generator that produces 32-bit words). Every float comes
+    // from exactly one draw; floats that a runtime assembles from several
+    // draws are not reproduced.
+    fn float_divisor(&self) -> f64 {
+        (u64::MAX as f64) + 1.0
+    }
+}
 
 struct Lcg {
     seed: i64,
@@ -37,13 +62,6 @@ impl Lcg {
         }
     }
 
-    pub fn srand(&mut self, seed: i64, iter: usize) {
-        self.seed = seed.into();
-        for _ in 0..iter {
-            self.rand();
-        }
-    }
-
     pub fn rand(&mut self) -> i64 {
         if self.modulo == 0 {
             self.seed = self.seed.wrapping_mul(self.mul);
@@ -64,6 +82,462 @@ impl Iterator for Lcg {
     }
 }
 
+impl Generator for Lcg {
+    fn srand(&mut self, seed: i64, offset: usize) {
+        self.seed = seed.into();
+        for _ in 0..offset {
+            self.rand();
+        }
+    }
+
+    fn default_offset(&self) -> usize {
+        self.offset
+    }
+
+    fn float_divisor(&self) -> f64 {
+        // The emitted value is (seed >> lsb) & bitmask, not the raw seed, so
+        // variants that extract a narrow word out of a wider modulus (e.g.
+        // ansic's 15-bit RAND_MAX out of a 2^31 modulus) must scale by that
+        // word's own range, not by the modulus.
+        let word_range = (self.bitmask as f64) + 1.0;
+        if self.modulo == 0 {
+            word_range
+        } else {
+            word_range.min(self.modulo as f64) // e.g. drand48's 2^48, or random0's 134456
+        }
+    }
+}
+
+// 32-bit Mersenne Twister (MT19937) core, shared by every variant that seeds
+// its 624-word state differently (plain `init_genrand` vs. CPython's keyed
+// `init_by_array`) but twists and tempers identically.
+// https://en.wikipedia.org/wiki/Mersenne_Twister#Algorithmic_detail
+fn mt19937_twist(state: &mut [u32; 624]) {
+    for i in 0..624 {
+        let x = (state[i] & 0x80000000) | (state[(i + 1) % 624] & 0x7fffffff);
+        let mut x_a = x >> 1;
+        if x & 1 != 0 {
+            x_a ^= 0x9908b0df;
+        }
+        state[i] = state[(i + 397) % 624] ^ x_a;
+    }
+}
+
+fn mt19937_temper(y: u32) -> u32 {
+    let mut y = y;
+    y ^= y >> 11;
+    y ^= (y << 7) & 0x9d2c5680;
+    y ^= (y << 15) & 0xefc60000;
+    y ^= y >> 18;
+    y
+}
+
+fn mt19937_init_genrand(state: &mut [u32; 624], seed: u32) {
+    state[0] = seed;
+    for i in 1..624 {
+        state[i] = 1812433253u32
+            .wrapping_mul(state[i - 1] ^ (state[i - 1] >> 30))
+            .wrapping_add(i as u32);
+    }
+}
+
+// CPython's `random.seed(a)` for an integer `a`: expand |a| into 32-bit
+// little-endian words and feed them through the reference `init_by_array`,
+// itself seeded via `init_genrand(19650218)`. This is NOT what C++'s
+// `std::mt19937(seed)` or PHP's `mt_srand` do (they call `init_genrand`
+// directly on the seed) - see `Mt19937` for that variant.
+// https://github.com/python/cpython/blob/main/Modules/_randommodule.c
+fn mt19937_init_by_array(state: &mut [u32; 624], key: &[u32]) {
+    mt19937_init_genrand(state, 19650218);
+    let mut i = 1;
+    let mut j = 0;
+    for _ in 0..std::cmp::max(624, key.len()) {
+        state[i] = (state[i] ^ ((state[i - 1] ^ (state[i - 1] >> 30)).wrapping_mul(1664525)))
+            .wrapping_add(key[j])
+            .wrapping_add(j as u32);
+        i += 1;
+        j += 1;
+        if i >= 624 {
+            state[0] = state[623];
+            i = 1;
+        }
+        if j >= key.len() {
+            j = 0;
+        }
+    }
+    for _ in 0..623 {
+        state[i] = (state[i] ^ ((state[i - 1] ^ (state[i - 1] >> 30)).wrapping_mul(1566083941)))
+            .wrapping_sub(i as u32);
+        i += 1;
+        if i >= 624 {
+            state[0] = state[623];
+            i = 1;
+        }
+    }
+    state[0] = 0x80000000;
+}
+
+fn python_seed_key(seed: i64) -> Vec<u32> {
+    let mut n = seed.unsigned_abs();
+    if n == 0 {
+        return vec![0];
+    }
+    let mut key = Vec::new();
+    while n > 0 {
+        key.push((n & 0xffff_ffff) as u32);
+        n >>= 32;
+    }
+    key
+}
+
+// MT19937 seeded via plain `init_genrand(seed)`, as used by C++'s
+// `std::mt19937(seed)` and PHP >= 7.1's `mt_srand`. Does NOT match Python's
+// `random` module, which re-keys the state through `init_by_array` - use
+// `Mt19937Python` for that.
+struct Mt19937 {
+    state: [u32; 624],
+    index: usize,
+}
+
+impl Mt19937 {
+    pub fn new() -> Self {
+        Self {
+            state: [0; 624],
+            index: 624,
+        }
+    }
+
+    fn rand(&mut self) -> u32 {
+        if self.index >= 624 {
+            mt19937_twist(&mut self.state);
+            self.index = 0;
+        }
+        let y = mt19937_temper(self.state[self.index]);
+        self.index += 1;
+        y
+    }
+}
+
+impl Iterator for Mt19937 {
+    type Item = i64;
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.rand() as i64)
+    }
+}
+
+impl Generator for Mt19937 {
+    fn srand(&mut self, seed: i64, offset: usize) {
+        mt19937_init_genrand(&mut self.state, seed as u32);
+        self.index = 624;
+        for _ in 0..offset {
+            self.rand();
+        }
+    }
+
+    fn float_divisor(&self) -> f64 {
+        4294967296.0 // 2^32, output is a single tempered word
+    }
+}
+
+// MT19937 seeded the way CPython's `random` module seeds an integer: the
+// seed is expanded into a key array and fed through `init_by_array`, not
+// `init_genrand` directly.
+struct Mt19937Python {
+    state: [u32; 624],
+    index: usize,
+}
+
+impl Mt19937Python {
+    pub fn new() -> Self {
+        Self {
+            state: [0; 624],
+            index: 624,
+        }
+    }
+
+    fn rand(&mut self) -> u32 {
+        if self.index >= 624 {
+            mt19937_twist(&mut self.state);
+            self.index = 0;
+        }
+        let y = mt19937_temper(self.state[self.index]);
+        self.index += 1;
+        y
+    }
+}
+
+impl Iterator for Mt19937Python {
+    type Item = i64;
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.rand() as i64)
+    }
+}
+
+impl Generator for Mt19937Python {
+    fn srand(&mut self, seed: i64, offset: usize) {
+        let key = python_seed_key(seed);
+        mt19937_init_by_array(&mut self.state, &key);
+        self.index = 624;
+        for _ in 0..offset {
+            self.rand();
+        }
+    }
+
+    fn float_divisor(&self) -> f64 {
+        4294967296.0 // 2^32, output is a single tempered word
+    }
+}
+
+// ISAAC, Bob Jenkins' 32-bit cipher-style generator used by the historical
+// Rust `librand` crate.
+// http://burtleburtle.net/bob/rand/isaacafa.html
+struct Isaac {
+    mm: [u32; 256],
+    aa: u32,
+    bb: u32,
+    cc: u32,
+    output: [u32; 256],
+    index: usize,
+}
+
+impl Isaac {
+    pub fn new() -> Self {
+        Self {
+            mm: [0; 256],
+            aa: 0,
+            bb: 0,
+            cc: 0,
+            output: [0; 256],
+            index: 256,
+        }
+    }
+
+    fn mix(state: &mut [u32; 8]) {
+        state[0] ^= state[1] << 11;
+        state[3] = state[3].wrapping_add(state[0]);
+        state[1] = state[1].wrapping_add(state[2]);
+        state[1] ^= state[2] >> 2;
+        state[4] = state[4].wrapping_add(state[1]);
+        state[2] = state[2].wrapping_add(state[3]);
+        state[2] ^= state[3] << 8;
+        state[5] = state[5].wrapping_add(state[2]);
+        state[3] = state[3].wrapping_add(state[4]);
+        state[3] ^= state[4] >> 16;
+        state[6] = state[6].wrapping_add(state[3]);
+        state[4] = state[4].wrapping_add(state[5]);
+        state[4] ^= state[5] << 10;
+        state[7] = state[7].wrapping_add(state[4]);
+        state[5] = state[5].wrapping_add(state[6]);
+        state[5] ^= state[6] >> 4;
+        state[0] = state[0].wrapping_add(state[5]);
+        state[6] = state[6].wrapping_add(state[7]);
+        state[6] ^= state[7] << 8;
+        state[1] = state[1].wrapping_add(state[6]);
+        state[7] = state[7].wrapping_add(state[0]);
+        state[7] ^= state[0] >> 9;
+        state[2] = state[2].wrapping_add(state[7]);
+        state[0] = state[0].wrapping_add(state[1]);
+    }
+
+    fn init(&mut self, seed: &[u32; 256]) {
+        let mut state = [0x9e3779b9u32; 8];
+        for _ in 0..4 {
+            Self::mix(&mut state);
+        }
+
+        for i in (0..256).step_by(8) {
+            for j in 0..8 {
+                state[j] = state[j].wrapping_add(seed[i + j]);
+            }
+            Self::mix(&mut state);
+            for j in 0..8 {
+                self.mm[i + j] = state[j];
+            }
+        }
+        for i in (0..256).step_by(8) {
+            for j in 0..8 {
+                state[j] = state[j].wrapping_add(self.mm[i + j]);
+            }
+            Self::mix(&mut state);
+            for j in 0..8 {
+                self.mm[i + j] = state[j];
+            }
+        }
+
+        self.aa = 0;
+        self.bb = 0;
+        self.cc = 0;
+        self.index = 256;
+    }
+
+    fn generate(&mut self) {
+        self.cc = self.cc.wrapping_add(1);
+        self.bb = self.bb.wrapping_add(self.cc);
+
+        for i in 0..256 {
+            let x = self.mm[i];
+            self.aa = match i % 4 {
+                0 => self.aa ^ (self.aa << 13),
+                1 => self.aa ^ (self.aa >> 6),
+                2 => self.aa ^ (self.aa << 2),
+                _ => self.aa ^ (self.aa >> 16),
+            };
+            self.aa = self.aa.wrapping_add(self.mm[(i + 128) % 256]);
+            let y = self.mm[(x >> 2) as usize % 256]
+                .wrapping_add(self.aa)
+                .wrapping_add(self.bb);
+            self.mm[i] = y;
+            self.bb = self.mm[(y >> 10) as usize % 256].wrapping_add(x);
+            self.output[i] = self.bb;
+        }
+        self.index = 0;
+    }
+
+    fn rand(&mut self) -> u32 {
+        if self.index >= 256 {
+            self.generate();
+        }
+        let v = self.output[self.index];
+        self.index += 1;
+        v
+    }
+}
+
+impl Iterator for Isaac {
+    type Item = i64;
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.rand() as i64)
+    }
+}
+
+impl Generator for Isaac {
+    fn srand(&mut self, seed: i64, offset: usize) {
+        let mut seed_words = [0u32; 256];
+        seed_words[0] = seed as u32;
+        seed_words[1] = (seed >> 32) as u32;
+        self.init(&seed_words);
+        for _ in 0..offset {
+            self.rand();
+        }
+    }
+
+    fn float_divisor(&self) -> f64 {
+        4294967296.0 // 2^32, output is a single 32-bit word
+    }
+}
+
+// ChaCha20 keystream, as found behind many modern language/runtime CSPRNGs.
+// https://datatracker.ietf.org/doc/html/rfc8439#section-2.3
+struct ChaCha20 {
+    key: [u32; 8],
+    nonce: [u32; 3],
+    counter: u32,
+    block: [u32; 16],
+    index: usize,
+}
+
+impl ChaCha20 {
+    pub fn new() -> Self {
+        Self {
+            key: [0; 8],
+            nonce: [0; 3],
+            counter: 0,
+            block: [0; 16],
+            index: 16,
+        }
+    }
+
+    fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+        state[a] = state[a].wrapping_add(state[b]);
+        state[d] ^= state[a];
+        state[d] = state[d].rotate_left(16);
+        state[c] = state[c].wrapping_add(state[d]);
+        state[b] ^= state[c];
+        state[b] = state[b].rotate_left(12);
+        state[a] = state[a].wrapping_add(state[b]);
+        state[d] ^= state[a];
+        state[d] = state[d].rotate_left(8);
+        state[c] = state[c].wrapping_add(state[d]);
+        state[b] ^= state[c];
+        state[b] = state[b].rotate_left(7);
+    }
+
+    fn refill(&mut self) {
+        const CONSTANTS: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+        let mut state = [0u32; 16];
+        state[0..4].copy_from_slice(&CONSTANTS);
+        state[4..12].copy_from_slice(&self.key);
+        state[12] = self.counter;
+        state[13..16].copy_from_slice(&self.nonce);
+
+        let mut working = state;
+        for _ in 0..10 {
+            Self::quarter_round(&mut working, 0, 4, 8, 12);
+            Self::quarter_round(&mut working, 1, 5, 9, 13);
+            Self::quarter_round(&mut working, 2, 6, 10, 14);
+            Self::quarter_round(&mut working, 3, 7, 11, 15);
+            Self::quarter_round(&mut working, 0, 5, 10, 15);
+            Self::quarter_round(&mut working, 1, 6, 11, 12);
+            Self::quarter_round(&mut working, 2, 7, 8, 13);
+            Self::quarter_round(&mut working, 3, 4, 9, 14);
+        }
+        for i in 0..16 {
+            working[i] = working[i].wrapping_add(state[i]);
+        }
+
+        self.block = working;
+        self.counter = self.counter.wrapping_add(1);
+        self.index = 0;
+    }
+
+    fn rand(&mut self) -> u32 {
+        if self.index >= 16 {
+            self.refill();
+        }
+        let v = self.block[self.index];
+        self.index += 1;
+        v
+    }
+}
+
+impl Iterator for ChaCha20 {
+    type Item = i64;
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.rand() as i64)
+    }
+}
+
+impl Generator for ChaCha20 {
+    fn srand(&mut self, seed: i64, offset: usize) {
+        self.key = [0; 8];
+        self.key[0] = seed as u32;
+        self.key[1] = (seed >> 32) as u32;
+        self.nonce = [0; 3];
+        self.counter = 0;
+        self.index = 16;
+        for _ in 0..offset {
+            self.rand();
+        }
+    }
+
+    fn float_divisor(&self) -> f64 {
+        4294967296.0 // 2^32, output is a single keystream word
+    }
+}
+
+// Dispatches an implementation name to its generator. LCG variants are all
+// handled by `get_lcg`; the remaining, structurally different families each
+// get their own constructor.
+fn get_generator(name: &str) -> Box<dyn Generator> {
+    match name {
+        "mt19937" => Box::new(Mt19937::new()),
+        "python_random" => Box::new(Mt19937Python::new()),
+        "isaac" => Box::new(Isaac::new()),
+        "chacha20" => Box::new(ChaCha20::new()),
+        _ => Box::new(get_lcg(name)),
+    }
+}
+
 // https://en.wikipedia.org/wiki/Linear_congruential_generator#Parameters_in_common_use
 // http://citeseer.ist.psu.edu/viewdoc/download?doi=10.1.1.53.3686&rep=rep1&type=pdf
 fn get_lcg(name: &str) -> Lcg {
@@ -108,6 +582,77 @@ fn get_lcg(name: &str) -> Lcg {
     };
 }
 
+// Aho-Corasick automaton over a set of needles, with every node's goto
+// table fully resolved (failure fallbacks baked in) so matching the
+// generated byte stream is a single table lookup per byte, independent
+// of the number of needles.
+struct AhoCorasick {
+    goto_table: Vec<[usize; 256]>,
+    output: Vec<Option<usize>>, // index into the needle list completed at this node, if any
+}
+
+impl AhoCorasick {
+    fn new(needles: &[Vec<u8>]) -> Self {
+        let mut children: Vec<[Option<usize>; 256]> = vec![[None; 256]];
+        let mut fail: Vec<usize> = vec![0];
+        let mut output: Vec<Option<usize>> = vec![None];
+
+        for (ns_i, needle) in needles.iter().enumerate() {
+            let mut node = 0;
+            for &b in needle {
+                node = match children[node][b as usize] {
+                    Some(next) => next,
+                    None => {
+                        children.push([None; 256]);
+                        fail.push(0);
+                        output.push(None);
+                        let next = children.len() - 1;
+                        children[node][b as usize] = Some(next);
+                        next
+                    }
+                };
+            }
+            output[node] = Some(ns_i);
+        }
+
+        // BFS over the trie to compute failure links and flatten them into
+        // goto_table, so the runtime walk never needs to follow a failure
+        // link itself.
+        let mut goto_table = vec![[0usize; 256]; children.len()];
+        let mut queue = VecDeque::new();
+
+        for b in 0..256 {
+            match children[0][b] {
+                Some(next) => {
+                    goto_table[0][b] = next;
+                    queue.push_back(next);
+                }
+                None => goto_table[0][b] = 0,
+            }
+        }
+
+        while let Some(node) = queue.pop_front() {
+            for b in 0..256 {
+                match children[node][b] {
+                    Some(next) => {
+                        fail[next] = goto_table[fail[node]][b];
+                        // union in the failure link's output so a needle
+                        // that is a suffix of another is still detected
+                        if output[next].is_none() {
+                            output[next] = output[fail[next]];
+                        }
+                        goto_table[node][b] = next;
+                        queue.push_back(next);
+                    }
+                    None => goto_table[node][b] = goto_table[fail[node]][b],
+                }
+            }
+        }
+
+        Self { goto_table, output }
+    }
+}
+
 trait DynInt: AsRef<[u8]> + IntoIterator<Item = u8> {
     fn to_bytes(val: i64) -> Self;
 }
@@ -134,37 +679,53 @@ impl DynInt for [u8; 8] {
 }
 
 fn iterate<'n, B: DynInt>(
-    rng: &mut Lcg,
+    rng: &mut dyn Generator,
     maxlen: usize,
     needles: &'n Vec<Vec<u8>>,
+    automaton: &AhoCorasick,
 ) -> (Option<&'n Vec<u8>>, usize) {
     let rand = rng.flat_map(B::to_bytes).take(maxlen);
     if needles.len() == 0 {
         let out: Vec<u8> = rand.collect();
         io::stdout().write_all(&out).unwrap();
     } else {
-        let mut matchcounts = vec![0; needles.len()]; // holds the number of matched chars for each needle
+        let min_needle_len = needles.iter().map(|n| n.len()).min().unwrap();
+        if min_needle_len > maxlen {
+            return (None, 0); // every needle is longer than the remainder of maxlen, so none can be in there
+        }
+
+        let mut node = 0;
         for (i, r) in rand.enumerate() {
-            let mut give_up = 0;
-            for (ns_i, mc) in matchcounts.iter_mut().enumerate() {
-                let needle = &needles[ns_i]; // &Vec<u8>
-                if needle.len() - *mc - 1 >= maxlen {
-                    give_up += 1; // needle is longer than remainder of maxlen, so it can't be in there
-                    continue;
-                }
-                if r == needle[*mc] {
-                    *mc += 1; // found a matching char
-                } else if r == needle[0] {
-                    *mc = 1; // edge case where we're setting the match count to 0, but index 0 of the needle matches the current char
-                } else {
-                    *mc = 0; // reset match count to 0
-                }
-                if *mc >= needle.len() {
-                    return (Some(needle), i + 1);
-                }
+            node = automaton.goto_table[node][r as usize];
+            if let Some(ns_i) = automaton.output[node] {
+                return (Some(&needles[ns_i]), i + 1);
             }
-            if give_up == needles.len() {
-                return (None, 0);
+        }
+    }
+    return (None, 0);
+}
+
+// Float counterpart of `iterate`: each generator step is scaled into [0, 1)
+// via the generator's own `float_divisor` and compared against `targets`
+// within `epsilon`, instead of being split into bytes and matched exactly.
+fn iterate_float(
+    rng: &mut dyn Generator,
+    maxlen: usize,
+    targets: &Vec<f64>,
+    epsilon: f64,
+) -> (Option<f64>, usize) {
+    let divisor = rng.float_divisor();
+    let floats = rng.take(maxlen).map(|v| (v as f64) / divisor);
+    if targets.len() == 0 {
+        for f in floats {
+            println!("{f}");
+        }
+    } else {
+        for (i, f) in floats.enumerate() {
+            for &t in targets {
+                if (f - t).abs() <= epsilon {
+                    return (Some(t), i + 1);
+                }
             }
         }
     }
@@ -179,11 +740,12 @@ fn run(
     offset: Option<usize>,
     intsize: u8,
     targets: &Vec<Vec<u8>>,
+    automaton: &AhoCorasick,
 ) {
-    let mut rng = get_lcg(imp);
+    let mut rng = get_generator(imp);
     let off = match offset {
         Some(x) => x,
-        _ => rng.offset,
+        _ => rng.default_offset(),
     };
     let bytes = (intsize as usize) / 8;
 
@@ -197,7 +759,7 @@ fn run(
 
     for seed in from..=to {
         rng.srand(seed as i64, off);
-        match fun(&mut rng, count * bytes, &targets) {
+        match fun(rng.as_mut(), count * bytes, &targets, automaton) {
             (Some(ref res), i) => {
                 println!(
                     "Found! {imp} seed={seed} bytes={}..{} (iteration={}..{}) -> 0x{}",
@@ -218,6 +780,144 @@ fn run(
     }
 }
 
+fn run_float(
+    imp: &str,
+    from: u64,
+    to: u64,
+    count: usize,
+    offset: Option<usize>,
+    targets: &Vec<f64>,
+    epsilon: f64,
+) {
+    let mut rng = get_generator(imp);
+    let off = match offset {
+        Some(x) => x,
+        _ => rng.default_offset(),
+    };
+
+    for seed in from..=to {
+        rng.srand(seed as i64, off);
+        match iterate_float(rng.as_mut(), count, &targets, epsilon) {
+            (Some(t), i) => {
+                println!(
+                    "Found! {imp} seed={seed} iteration={}..{} -> {t}",
+                    off + i - 1,
+                    off + i
+                );
+                return;
+            }
+            _ => (),
+        }
+    }
+
+    if targets.len() > 0 {
+        process::exit(1);
+    }
+}
+
+// Box-Muller polar (Marsaglia) transform layered on top of the float path:
+// two uniforms are consumed per accepted pair and turned into two standard
+// normal samples. Rejected pairs consume draws without emitting anything,
+// so `draws` (not the emitted count) is what tracks true stream position.
+fn iterate_normal(
+    rng: &mut dyn Generator,
+    maxlen: usize,
+    targets: &Vec<f64>,
+    epsilon: f64,
+) -> (Option<f64>, usize, usize) {
+    let divisor = rng.float_divisor();
+    let mut draws = 0;
+    // Second output of an accepted pair (the first is emitted right away),
+    // waiting to be emitted next, plus the draw range (draws consumed
+    // before the pair's search started..after it was accepted) that pair
+    // actually spans, including any rejected attempts along the way.
+    let mut pending: Option<(f64, usize, usize)> = None;
+    let mut emitted = 0;
+
+    while emitted < maxlen {
+        let (normal, draws_before, draws_after) = match pending.take() {
+            Some(v) => v,
+            None => {
+                let draws_before = draws;
+                loop {
+                    let u1 = match rng.next() {
+                        Some(v) => {
+                            draws += 1;
+                            (v as f64) / divisor
+                        }
+                        None => return (None, 0, 0),
+                    };
+                    let u2 = match rng.next() {
+                        Some(v) => {
+                            draws += 1;
+                            (v as f64) / divisor
+                        }
+                        None => return (None, 0, 0),
+                    };
+
+                    let v1 = 2.0 * u1 - 1.0;
+                    let v2 = 2.0 * u2 - 1.0;
+                    let s = v1 * v1 + v2 * v2;
+                    if s >= 1.0 || s == 0.0 {
+                        continue; // outside the unit circle (or degenerate), draw another pair
+                    }
+
+                    let scale = (-2.0 * s.ln() / s).sqrt();
+                    pending = Some((v2 * scale, draws_before, draws));
+                    break (v1 * scale, draws_before, draws);
+                }
+            }
+        };
+        emitted += 1;
+
+        if targets.len() == 0 {
+            println!("{normal}");
+        } else {
+            for &t in targets {
+                if (normal - t).abs() <= epsilon {
+                    return (Some(t), draws_before, draws_after);
+                }
+            }
+        }
+    }
+    return (None, 0, 0);
+}
+
+fn run_normal(
+    imp: &str,
+    from: u64,
+    to: u64,
+    count: usize,
+    offset: Option<usize>,
+    targets: &Vec<f64>,
+    epsilon: f64,
+) {
+    let mut rng = get_generator(imp);
+    let off = match offset {
+        Some(x) => x,
+        _ => rng.default_offset(),
+    };
+
+    for seed in from..=to {
+        rng.srand(seed as i64, off);
+        match iterate_normal(rng.as_mut(), count, &targets, epsilon) {
+            (Some(t), draws_before, draws_after) => {
+                println!(
+                    "Found! {imp} seed={seed} iteration={}..{} -> {t}",
+                    off + draws_before,
+                    off + draws_after
+                );
+                return;
+            }
+            _ => (),
+        }
+    }
+
+    if targets.len() > 0 {
+        process::exit(1);
+    }
+}
+
 fn main() {
     let matches = command!()
         .arg(arg!(-i --impl <IMPLS> "LCG implementations to use (comma separated), or \"all\". See --help for full list.").required(true).value_delimiter(',').long_help("LCG implementations to use (comma separated), or \"all\".\n".to_owned() + &IMPLS.join(", ")))
@@ -246,10 +946,21 @@ fn main() {
                 .default_value("64")
         )
         .arg(
-            arg!(-m --match <FILE> "File with hex encoded matches to search for (whitespace separated)")
+            arg!(-m --match <FILE> "File with hex encoded matches to search for (whitespace separated). In --float mode, decimal literals or big-endian IEEE-754 hex encodings.")
                 .required(false)
                 .value_parser(value_parser!(PathBuf))
         )
+        .arg(
+            arg!(--float "Scale generator output to an f64 in [0, 1) and match against decimal/IEEE-754 targets instead of raw integer bytes")
+        )
+        .arg(
+            arg!(--epsilon <VALUE> "Tolerance used to compare floats in --float/--normal mode")
+                .value_parser(value_parser!(f64))
+                .default_value("0.000001")
+        )
+        .arg(
+            arg!(--normal "Layer a Box-Muller polar transform on top of float mode and match standard-normal targets instead of uniform ones")
+        )
         .get_matches();
 
     let mut impls: Vec<_> = matches
@@ -262,21 +973,64 @@ fn main() {
     let count = matches.get_one::<usize>("count").unwrap();
     let off = matches.get_one::<usize>("offset");
     let size = matches.get_one::<u8>("size").unwrap();
+    let float_mode = matches.get_flag("float");
+    let normal_mode = matches.get_flag("normal");
+    let epsilon = matches.get_one::<f64>("epsilon").unwrap();
 
     let input = match matches.get_one::<PathBuf>("match") {
         Some(file_path) => fs::read_to_string(file_path).unwrap(),
         _ => String::new(),
     };
-    let targets = input
-        .split_whitespace()
-        .map(|s| hex::decode(s).expect("hex decoding failed!"))
-        .collect();
 
     if impls[0] == "all" {
         impls = IMPLS.to_vec();
     }
 
-    for imp in impls {
-        run(imp, *from, *to, *count, off.copied(), *size, &targets);
+    if normal_mode {
+        let targets: Vec<f64> = input
+            .split_whitespace()
+            .map(|s| parse_float_target(s).expect("float decoding failed!"))
+            .collect();
+
+        for imp in impls {
+            run_normal(imp, *from, *to, *count, off.copied(), &targets, *epsilon);
+        }
+    } else if float_mode {
+        let targets: Vec<f64> = input
+            .split_whitespace()
+            .map(|s| parse_float_target(s).expect("float decoding failed!"))
+            .collect();
+
+        for imp in impls {
+            run_float(imp, *from, *to, *count, off.copied(), &targets, *epsilon);
+        }
+    } else {
+        let targets: Vec<Vec<u8>> = input
+            .split_whitespace()
+            .map(|s| hex::decode(s).expect("hex decoding failed!"))
+            .collect();
+
+        let automaton = AhoCorasick::new(&targets);
+        for imp in impls {
+            run(imp, *from, *to, *count, off.copied(), *size, &targets, &automaton);
+        }
+    }
+}
+
+// Parses a single --match token in --float mode: either a decimal literal
+// (as users typically see in leaked output) or the big-endian IEEE-754
+// hex encoding of one, mirroring the integer path's hex::decode.
+fn parse_float_target(s: &str) -> Option<f64> {
+    // 16 hex digits are an encoding even if they also read as a decimal
+    // (2.0 is 4000000000000000)
+    if s.len() == 16 && s.chars().all(|c| c.is_ascii_hexdigit()) {
+        let arr: [u8; 8] = hex::decode(s).ok()?.try_into().ok()?;
+        return Some(f64::from_be_bytes(arr));
+    }
+    if let Ok(f) = s.parse::<f64>() {
+        return Some(f);
     }
+    let bytes = hex::decode(s).ok()?;
+    let arr: [u8; 8] = bytes.try_into().ok()?;
+    Some(f64::from_be_bytes(arr))
 }